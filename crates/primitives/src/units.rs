@@ -0,0 +1,157 @@
+//! Conversions between Core Coin's base `wei` unit and larger denominations,
+//! borrowed from ethers-core's `utils` (`format_units`/`format_ether`/`parse_units`)
+//! pattern of scaling a `U256` by a decimal exponent.
+use crate::U256;
+use std::fmt;
+
+/// Number of decimals for the base unit, `wei`.
+pub const WEI_DECIMALS: u32 = 0;
+/// Number of decimals for `gwei` (10^9 wei).
+pub const GWEI_DECIMALS: u32 = 9;
+/// Number of decimals for the native Core Coin denomination, `core` (10^18 wei).
+pub const CORE_DECIMALS: u32 = 18;
+
+/// Error returned when converting between `U256` base units and a decimal string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitsError {
+    /// The input string wasn't a valid decimal number, or had more fractional
+    /// digits than `decimals` allows.
+    InvalidDecimal,
+    /// The value didn't fit in a `U256` once scaled to base units.
+    Overflow,
+}
+
+impl fmt::Display for UnitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            UnitsError::InvalidDecimal => "invalid decimal string",
+            UnitsError::Overflow => "value overflows U256 once scaled to base units",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for UnitsError {}
+
+/// Formats `amount` (in base units, i.e. wei) as a fixed-point decimal string with
+/// up to `decimals` digits after the point. The fractional remainder is kept in
+/// full, not truncated away as plain integer division (`amount / 10^decimals`) would.
+pub fn format_units(amount: U256, decimals: u32) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+
+    let base = U256::from(10u64).pow(U256::from(decimals));
+    let integer = amount / base;
+    let remainder = amount % base;
+
+    let remainder_str = remainder.to_string();
+    let fraction = format!(
+        "{}{remainder_str}",
+        "0".repeat(decimals as usize - remainder_str.len())
+    );
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Formats `amount` in the native `core` denomination ([`CORE_DECIMALS`]).
+pub fn format_core(amount: U256) -> String {
+    format_units(amount, CORE_DECIMALS)
+}
+
+/// Parses a `"1.25"`-style decimal string into base units (wei), scaling by `decimals`.
+pub fn parse_units(s: &str, decimals: u32) -> Result<U256, UnitsError> {
+    let mut parts = s.splitn(2, '.');
+    let integer_part = parts.next().unwrap_or("");
+    let fraction_part = parts.next().unwrap_or("");
+
+    if (integer_part.is_empty() && fraction_part.is_empty())
+        || !integer_part.chars().all(|c| c.is_ascii_digit())
+        || !fraction_part.chars().all(|c| c.is_ascii_digit())
+        || fraction_part.len() > decimals as usize
+    {
+        return Err(UnitsError::InvalidDecimal);
+    }
+
+    let integer = if integer_part.is_empty() {
+        U256::ZERO
+    } else {
+        integer_part
+            .parse::<U256>()
+            .map_err(|_| UnitsError::Overflow)?
+    };
+
+    let padded_fraction = format!(
+        "{fraction_part}{}",
+        "0".repeat(decimals as usize - fraction_part.len())
+    );
+    let fraction = if padded_fraction.is_empty() {
+        U256::ZERO
+    } else {
+        padded_fraction
+            .parse::<U256>()
+            .map_err(|_| UnitsError::Overflow)?
+    };
+
+    let base = U256::from(10u64)
+        .checked_pow(U256::from(decimals))
+        .ok_or(UnitsError::Overflow)?;
+    let scaled_integer = integer.checked_mul(base).ok_or(UnitsError::Overflow)?;
+    scaled_integer
+        .checked_add(fraction)
+        .ok_or(UnitsError::Overflow)
+}
+
+/// Parses a `"1.25"`-style decimal string into base units in the native `core`
+/// denomination ([`CORE_DECIMALS`]).
+pub fn parse_core(s: &str) -> Result<U256, UnitsError> {
+    parse_units(s, CORE_DECIMALS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_units_keeps_fraction() {
+        assert_eq!(format_units(U256::from(1_250_000_000_000_000_000u128), 18), "1.25");
+    }
+
+    #[test]
+    fn test_format_units_whole_number() {
+        assert_eq!(format_units(U256::from(2_000_000_000_000_000_000u128), 18), "2");
+    }
+
+    #[test]
+    fn test_format_units_zero_decimals() {
+        assert_eq!(format_units(U256::from(42u64), 0), "42");
+    }
+
+    #[test]
+    fn test_parse_units_round_trip() {
+        let amount = parse_units("1.25", 18).unwrap();
+        assert_eq!(amount, U256::from(1_250_000_000_000_000_000u128));
+        assert_eq!(format_units(amount, 18), "1.25");
+    }
+
+    #[test]
+    fn test_parse_units_too_many_fraction_digits() {
+        assert_eq!(parse_units("1.1234", 2), Err(UnitsError::InvalidDecimal));
+    }
+
+    #[test]
+    fn test_parse_units_invalid_string() {
+        assert_eq!(parse_units("abc", 18), Err(UnitsError::InvalidDecimal));
+    }
+
+    #[test]
+    fn test_parse_core_and_format_core() {
+        let amount = parse_core("1.5").unwrap();
+        assert_eq!(format_core(amount), "1.5");
+    }
+}
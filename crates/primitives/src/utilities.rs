@@ -7,12 +7,27 @@ const MAINNET: &str = "cb";
 const TESTNET: &str = "ab";
 const PRIVATE: &str = "ce";
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NetworkType {
     Mainnet,
     Testnet,
     Private,
 }
 
+/// Returns the [`NetworkType`] an ICAN address belongs to, based on its
+/// two-character prefix (`cb`/`ab`/`ce`), or `None` if the prefix is unrecognized.
+///
+/// Mirrors rust-bitcoin's `Address`/`Network` coupling, where an address
+/// carries enough information to tell you which network it was minted for.
+pub fn network_of(addr: &B176) -> Option<NetworkType> {
+    match addr[0] {
+        0xcb => Some(NetworkType::Mainnet),
+        0xab => Some(NetworkType::Testnet),
+        0xce => Some(NetworkType::Private),
+        _ => None,
+    }
+}
+
 pub const KECCAK_EMPTY: B256 = B256(hex!(
     "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
 ));
@@ -32,8 +47,11 @@ pub fn create_address(caller: B176, nonce: u64) -> B176 {
     // Get the last 20 bytes of the hash
     let addr = B160(out[12..].try_into().unwrap());
 
+    // Deployed contracts live on the same network as their deployer
+    let network = network_of(&caller).unwrap_or(NetworkType::Mainnet);
+
     // Calculate the checksum and add the network prefix
-    to_ican(&addr, &NetworkType::Mainnet)
+    to_ican(&addr, &network)
 }
 
 /// Returns the address for the `CREATE2` scheme: [`CreateScheme::Create2`]
@@ -47,8 +65,11 @@ pub fn create2_address(caller: B176, code_hash: B256, salt: U256) -> B176 {
     // Get the last 20 bytes of the hash
     let addr = B160(hasher.finalize().as_slice()[12..].try_into().unwrap());
 
+    // Deployed contracts live on the same network as their deployer
+    let network = network_of(&caller).unwrap_or(NetworkType::Mainnet);
+
     // Calculate the checksum and add the network prefix
-    to_ican(&addr, &NetworkType::Mainnet)
+    to_ican(&addr, &network)
 }
 
 fn to_ican(addr: &B160, network: &NetworkType) -> B176 {
@@ -114,6 +135,117 @@ fn construct_ican_address(prefix: &str, checksum: &u64, addr: &B160) -> B176 {
     }
 }
 
+/// Errors returned while parsing or validating an ICAN address string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcanError {
+    /// The string was not 44 hex characters (2 prefix + 2 checksum + 40 account).
+    InvalidLength,
+    /// The two-character prefix isn't one of `cb`/`ab`/`ce`.
+    UnknownPrefix,
+    /// The body contains characters that aren't valid hex digits.
+    InvalidHex,
+    /// The ISO-7064 mod-97-10 check digits don't match the account portion.
+    ChecksumMismatch,
+}
+
+impl std::fmt::Display for IcanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            IcanError::InvalidLength => "ICAN address must be 44 hex characters long",
+            IcanError::UnknownPrefix => "ICAN address has an unrecognized network prefix",
+            IcanError::InvalidHex => "ICAN address body is not valid hex",
+            IcanError::ChecksumMismatch => "ICAN address checksum is invalid",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for IcanError {}
+
+/// Verifies the ISO-7064 mod-97-10 (IBAN-style) check digits embedded in an ICAN address.
+///
+/// The 20-byte account portion is moved ahead of the prefix and checksum (the
+/// four-digit head is rotated to the tail), each hex digit is expanded to its
+/// decimal value, and the resulting decimal string is folded modulo 97 with
+/// the same recurrence used by [`calculate_checksum`]. A valid address yields
+/// a remainder of `1`.
+pub fn validate_ican(addr: &B176) -> bool {
+    // We have to use the Debug trait for addr https://github.com/paritytech/parity-common/issues/656
+    let addr_str = format!("{addr:?}").replace("0x", "");
+
+    let prefix = &addr_str[0..2];
+    let checksum = &addr_str[2..4];
+    let account = &addr_str[4..44];
+
+    let rearranged = format!("{account}{prefix}{checksum}");
+    let number_str = rearranged
+        .chars()
+        .map(|x| x.to_digit(16).expect("Invalid Address").to_string())
+        .collect::<String>();
+
+    let result = number_str.chars().fold(0, |acc, ch| {
+        let digit = ch.to_digit(10).expect("Invalid Digit") as u64;
+        (acc * 10 + digit) % 97
+    });
+
+    result == 1
+}
+
+/// Parses and checksum-validates an ICAN address string, analogous to how
+/// rust-bitcoin's `Address::from_str` validates its own encoding.
+pub fn parse_ican(s: &str) -> Result<B176, IcanError> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+
+    if s.len() != 44 {
+        return Err(IcanError::InvalidLength);
+    }
+
+    let prefix = &s[0..2];
+    if prefix != MAINNET && prefix != TESTNET && prefix != PRIVATE {
+        return Err(IcanError::UnknownPrefix);
+    }
+
+    let addr = B176::from_str(s).map_err(|_| IcanError::InvalidHex)?;
+
+    if !validate_ican(&addr) {
+        return Err(IcanError::ChecksumMismatch);
+    }
+
+    Ok(addr)
+}
+
+/// Formats an ICAN address in space-separated four-character groups, e.g.
+/// `cb41 485a 4227 7ed7 f4fe a81c c12e fd12 d57d cb54 9150`, the conventional
+/// human-readable grouping for IBAN-style account numbers.
+pub fn format_grouped(addr: &B176) -> String {
+    // We have to use the Debug trait for addr https://github.com/paritytech/parity-common/issues/656
+    let addr_str = format!("{addr:?}").replace("0x", "");
+
+    addr_str
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| std::str::from_utf8(chunk).expect("hex string is valid utf8"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Wraps a [`B176`] to print it grouped via [`format_grouped`], keeping the tight
+/// hex form (`B176`'s own `Debug`/hex output) as the canonical on-chain representation.
+pub struct GroupedIcan<'a>(pub &'a B176);
+
+impl<'a> std::fmt::Display for GroupedIcan<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format_grouped(self.0))
+    }
+}
+
+/// Parses a grouped (or otherwise whitespace-separated) ICAN address string,
+/// stripping internal whitespace before feeding it to the checked [`parse_ican`].
+pub fn parse_grouped_ican(s: &str) -> Result<B176, IcanError> {
+    let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    parse_ican(&compact)
+}
+
 /// Serde functions to serde as [bytes::Bytes] hex string
 #[cfg(feature = "serde")]
 pub mod serde_hex_bytes {
@@ -221,6 +353,98 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_ican_valid() {
+        let addr = B176::from_str("cb41485a42277ed7f4fea81cc12efd12d57dcb549150").unwrap();
+        assert!(validate_ican(&addr));
+    }
+
+    #[test]
+    fn test_validate_ican_invalid_checksum() {
+        let addr = B176::from_str("cb42485a42277ed7f4fea81cc12efd12d57dcb549150").unwrap();
+        assert!(!validate_ican(&addr));
+    }
+
+    #[test]
+    fn test_parse_ican_valid() {
+        let addr = parse_ican("cb41485a42277ed7f4fea81cc12efd12d57dcb549150").unwrap();
+        assert_eq!(
+            addr,
+            B176::from_str("cb41485a42277ed7f4fea81cc12efd12d57dcb549150").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_ican_invalid_length() {
+        assert_eq!(parse_ican("cb41485a"), Err(IcanError::InvalidLength));
+    }
+
+    #[test]
+    fn test_parse_ican_unknown_prefix() {
+        assert_eq!(
+            parse_ican("zz41485a42277ed7f4fea81cc12efd12d57dcb549150"),
+            Err(IcanError::UnknownPrefix)
+        );
+    }
+
+    #[test]
+    fn test_parse_ican_invalid_hex() {
+        assert_eq!(
+            parse_ican("cb41zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz"),
+            Err(IcanError::InvalidHex)
+        );
+    }
+
+    #[test]
+    fn test_parse_ican_checksum_mismatch() {
+        assert_eq!(
+            parse_ican("cb42485a42277ed7f4fea81cc12efd12d57dcb549150"),
+            Err(IcanError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_format_grouped() {
+        let addr = B176::from_str("cb41485a42277ed7f4fea81cc12efd12d57dcb549150").unwrap();
+        assert_eq!(
+            format_grouped(&addr),
+            "cb41 485a 4227 7ed7 f4fe a81c c12e fd12 d57d cb54 9150"
+        );
+        assert_eq!(
+            GroupedIcan(&addr).to_string(),
+            "cb41 485a 4227 7ed7 f4fe a81c c12e fd12 d57d cb54 9150"
+        );
+    }
+
+    #[test]
+    fn test_parse_grouped_ican() {
+        let addr =
+            parse_grouped_ican("cb41 485a 4227 7ed7 f4fe a81c c12e fd12 d57d cb54 9150").unwrap();
+        assert_eq!(
+            addr,
+            B176::from_str("cb41485a42277ed7f4fea81cc12efd12d57dcb549150").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_network_of() {
+        let mainnet = B176::from_str("cb72e8cF4629ACB360350399B6CFF367A97CF36E62B9").unwrap();
+        let testnet = B176::from_str("ab72e8cF4629ACB360350399B6CFF367A97CF36E62B9").unwrap();
+        let private = B176::from_str("ce72e8cF4629ACB360350399B6CFF367A97CF36E62B9").unwrap();
+
+        assert_eq!(network_of(&mainnet), Some(NetworkType::Mainnet));
+        assert_eq!(network_of(&testnet), Some(NetworkType::Testnet));
+        assert_eq!(network_of(&private), Some(NetworkType::Private));
+    }
+
+    #[test]
+    fn test_create_address_preserves_caller_network() {
+        let caller = B176::from_str("ab72e8cF4629ACB360350399B6CFF367A97CF36E62B9").unwrap();
+        let ican_address = create_address(caller, 1);
+
+        assert_eq!(network_of(&ican_address), Some(NetworkType::Testnet));
+    }
+
     #[test]
     fn test_calculate_checksum_address() {
         let address = B160::from_str("e8cF4629ACB360350399B6CFF367A97CF36E62B9").unwrap();